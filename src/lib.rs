@@ -120,6 +120,96 @@ impl<T> ReusingVec<T> {
             None
         }
     }
+    /// Retains only the logical elements for which the predicate returns `true`
+    ///
+    /// Unlike [`Vec::retain`], the elements for which the predicate returns `false` are not dropped;
+    /// they are moved past the logical end so they can be recycled by a later
+    /// [`push_with`](Self::push_with) / [`push_mut`](Self::push_mut).
+    #[inline]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|element| f(element));
+    }
+    /// Retains only the logical elements for which the predicate returns `true`, passing a mutable
+    /// reference to each element
+    ///
+    /// As with [`retain`](Self::retain), rejected elements are parked past the logical end rather
+    /// than dropped.
+    #[inline]
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        let mut write = 0;
+        for read in 0..self.logical_len {
+            if f(self.contents.get_mut(read).unwrap()) {
+                if read != write {
+                    self.contents.swap(read, write);
+                }
+                write += 1;
+            }
+        }
+        self.logical_len = write;
+    }
+    /// Removes the logical element at `index` by swapping it with the last logical element
+    ///
+    /// This does not return the element by value or drop it; instead the removed element is parked
+    /// just past the logical end and a mutable reference to it is returned, so it remains available
+    /// for the next [`push_with`](Self::push_with) / [`push_mut`](Self::push_mut).  As with
+    /// [`Vec::swap_remove`] this does not preserve the order of the remaining elements, but it is O(1).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn swap_remove(&mut self, index: usize) -> &mut T {
+        assert!(index < self.logical_len, "swap_remove index (is {index}) should be < len (is {})", self.logical_len);
+        let last = self.logical_len - 1;
+        self.contents.swap(index, last);
+        self.logical_len = last;
+        self.contents.get_mut(last).unwrap()
+    }
+    /// Removes the logical element at `index`, shifting the elements after it down
+    ///
+    /// Like [`swap_remove`](Self::swap_remove) the element is kept alive and parked just past the
+    /// logical end rather than dropped, and a mutable reference to it is returned.  Unlike
+    /// `swap_remove` the order of the remaining elements is preserved, so this is O(n).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> &mut T {
+        assert!(index < self.logical_len, "removal index (is {index}) should be < len (is {})", self.logical_len);
+        self.contents[index..self.logical_len].rotate_left(1);
+        self.logical_len -= 1;
+        self.contents.get_mut(self.logical_len).unwrap()
+    }
+    /// Returns the total number of elements the vector can hold without reallocating
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.contents.capacity()
+    }
+    /// Reserves capacity for at least `additional` more elements, forwarding to the inner [`Vec`]
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.contents.reserve(additional);
+    }
+    /// Reserves the minimum capacity for at least `additional` more elements, forwarding to the inner [`Vec`]
+    #[inline]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.contents.reserve_exact(additional);
+    }
+    /// Returns the number of inactive elements being kept alive past the logical end for reuse
+    #[inline]
+    pub fn inactive_len(&self) -> usize {
+        self.contents.len() - self.logical_len
+    }
+    /// Drops the inactive elements parked past the logical end and releases their memory
+    ///
+    /// This reclaims the allocations held by elements left alive for reuse (including their inner
+    /// heap allocations), which is worth doing after a buffer that briefly grew large is now small.
+    #[inline]
+    pub fn shrink_to_active(&mut self) {
+        self.contents.truncate(self.logical_len);
+        self.contents.shrink_to_fit();
+    }
 }
 
 impl<T> AsMut<[T]> for ReusingVec<T> {
@@ -238,6 +328,58 @@ impl<T: ReusableElement> ReusingVec<T> {
         self.logical_len += 1;
         element
     }
+    /// Appends an element for each item of `iter`, reinitializing a parked slot in place from the item
+    ///
+    /// This is the reuse-friendly counterpart to [`Extend`]: rather than taking elements by value
+    /// (which would discard the inner allocations of any parked slot), each source item is fed to
+    /// `reset_f` together with a mutable reference to a recycled element.  A new element is created
+    /// with [`ReusableElement::new`] only when the buffer has no parked slot left and must grow.
+    ///
+    /// A recycled slot is reset before `reset_f` runs, so the closure always sees a fresh element
+    /// regardless of whether the buffer grew.
+    #[inline]
+    pub fn push_from_iter<I, F>(&mut self, iter: I, mut reset_f: F)
+        where
+        I: IntoIterator,
+        F: FnMut(&mut T, I::Item)
+    {
+        for item in iter {
+            if self.logical_len < self.contents.len() {
+                let slot = self.contents.get_mut(self.logical_len).unwrap();
+                slot.reset();
+                reset_f(slot, item);
+            } else {
+                let mut element = T::new();
+                reset_f(&mut element, item);
+                self.contents.push(element);
+            }
+            self.logical_len += 1;
+        }
+    }
+    /// Resizes the logical length to `n`, recycling parked slots through `f`
+    ///
+    /// This is analogous to [`Vec::resize_with`], but when growing it reuses the elements parked
+    /// past the logical end (reset and passed to `f`) instead of constructing fresh ones, and when
+    /// shrinking it parks the surplus elements rather than dropping them.
+    #[inline]
+    pub fn fill_active_with<F: FnMut(&mut T)>(&mut self, n: usize, mut f: F) {
+        if n <= self.logical_len {
+            self.logical_len = n;
+            return;
+        }
+        while self.logical_len < n {
+            if self.logical_len < self.contents.len() {
+                let slot = self.contents.get_mut(self.logical_len).unwrap();
+                slot.reset();
+                f(slot);
+            } else {
+                let mut element = T::new();
+                f(&mut element);
+                self.contents.push(element);
+            }
+            self.logical_len += 1;
+        }
+    }
 }
 
 /// Implemented on element types to provide a unified interface for creating a new element and
@@ -351,3 +493,83 @@ impl<A: smallvec::Array> ReusableElement for smallvec::SmallVec<A> {
         Self::new()
     }
 }
+
+#[test]
+fn retain_recycles_rejected_elements() {
+    let mut vec: ReusingVec<String> = ReusingVec::new();
+    for word in ["a", "bb", "ccc", "dddd"] {
+        vec.push_mut().push_str(word);
+    }
+    // Rejected ("bb", "dddd") are parked, not dropped, so they keep their allocations
+    vec.retain(|s| s.len() % 2 == 1);
+    assert_eq!(vec.len(), 2);
+    assert_eq!(&*vec, &["a", "ccc"]);
+
+    // The two parked slots are reused by the next pushes without reallocating
+    let cap_before = vec.contents.len();
+    vec.push_mut().push('e');
+    vec.push_mut().push('f');
+    assert_eq!(vec.contents.len(), cap_before);
+    assert_eq!(&*vec, &["a", "ccc", "e", "f"]);
+}
+
+#[test]
+fn swap_remove_and_remove_recycle() {
+    let mut vec: ReusingVec<String> = ReusingVec::new();
+    for word in ["a", "b", "c", "d"] {
+        vec.push_mut().push_str(word);
+    }
+
+    let removed = vec.swap_remove(1);
+    assert_eq!(removed, "b");
+    assert_eq!(&*vec, &["a", "d", "c"]);
+
+    let removed = vec.remove(0);
+    assert_eq!(removed, "a");
+    assert_eq!(&*vec, &["d", "c"]);
+
+    // Both parked elements are recycled without growing the backing allocation
+    let cap_before = vec.contents.len();
+    vec.push_mut().push('e');
+    vec.push_mut().push('f');
+    assert_eq!(vec.contents.len(), cap_before);
+    assert_eq!(&*vec, &["d", "c", "e", "f"]);
+}
+
+#[test]
+fn shrink_to_active_frees_parked_elements() {
+    let mut vec: ReusingVec<Vec<u8>> = ReusingVec::new();
+    for _ in 0..8 {
+        vec.push_mut().extend_from_slice(&[1, 2, 3, 4]);
+    }
+    vec.truncate(2);
+    assert_eq!(vec.inactive_len(), 6);
+
+    vec.shrink_to_active();
+    assert_eq!(vec.inactive_len(), 0);
+    assert_eq!(vec.len(), 2);
+    assert!(vec.capacity() >= 2);
+}
+
+#[test]
+fn push_from_iter_and_fill_active_with_recycle() {
+    let mut vec: ReusingVec<String> = ReusingVec::new();
+    vec.push_from_iter(["ab", "cd", "ef"], |slot, item| {
+        slot.push_str(item);
+    });
+    assert_eq!(&*vec, &["ab", "cd", "ef"]);
+
+    // Reloading a shorter batch reuses the parked slots without reallocating
+    vec.clear();
+    let cap_before = vec.contents.len();
+    vec.push_from_iter([1, 2], |slot, n| {
+        use core::fmt::Write;
+        write!(slot, "#{n}").unwrap();
+    });
+    assert_eq!(&*vec, &["#1", "#2"]);
+    assert_eq!(vec.contents.len(), cap_before);
+
+    vec.fill_active_with(3, |slot| slot.push('z'));
+    assert_eq!(&*vec, &["#1", "#2", "z"]);
+    assert_eq!(vec.contents.len(), cap_before);
+}
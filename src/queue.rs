@@ -6,16 +6,27 @@ use crate::*;
 
 /// A structure similar to [`ReusingVec`](crate::ReusingVec), but with support for a
 /// [`pop_front`](Self::pop_front) operation
+///
+/// Internally the elements are stored in a growable circular buffer, in the style of
+/// [`VecDeque`](alloc::collections::VecDeque).  Every slot of `contents` is kept physically
+/// initialized, and the logical element `i` lives at physical index `(head + i) % contents.len()`.
+/// A [`pop_front`](Self::pop_front) leaves the popped element live so that a later
+/// [`push_with`](Self::push_with) / [`push_mut`](Self::push_mut) can recycle it, which keeps a
+/// steady FIFO workload from growing the backing allocation without bound.
+///
+/// Because the storage is circular it is not a single contiguous slice, so the logical contents are
+/// accessed through [`as_slices`](Self::as_slices) / [`as_mut_slices`](Self::as_mut_slices), or
+/// rearranged into one slice with [`make_contiguous`](Self::make_contiguous).
 #[derive(Clone, Default)]
 pub struct ReusingQueue<T> {
-    logical_start: usize,
-    logical_end: usize,
+    head: usize,
+    len: usize,
     contents: Vec<T>
 }
 
 impl<T> core::fmt::Debug for ReusingQueue<T> where T: core::fmt::Debug {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
-        write!(f, "{:?}", self as &[_])
+        f.debug_list().entries(self.iter()).finish()
     }
 }
 
@@ -24,8 +35,8 @@ impl<T> ReusingQueue<T> {
     #[inline]
     pub const fn new() -> Self {
         Self {
-            logical_start: 0,
-            logical_end: 0,
+            head: 0,
+            len: 0,
             contents: Vec::new()
         }
     }
@@ -33,39 +44,86 @@ impl<T> ReusingQueue<T> {
     #[inline]
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
-            logical_start: 0,
-            logical_end: 0,
+            head: 0,
+            len: 0,
             contents: Vec::with_capacity(capacity)
         }
     }
     /// Clears the vector, logically removing all values, but not dropping them
     #[inline]
     pub fn clear(&mut self) {
-        self.logical_start = 0;
-        self.logical_end = 0;
+        self.len = 0;
     }
     /// Shortens the vector, keeping the first `len` elements and logically removing the rest
     ///
     /// If `len` is greater or equal to the vector’s current logical length, this has no effect.
     #[inline]
     pub fn truncate(&mut self, len: usize) {
-        if len == 0 {
-            self.clear()
-        } else {
-            if len < (self.logical_end - self.logical_start) {
-                self.logical_end = self.logical_start + len;
-            }
+        if len < self.len {
+            self.len = len;
         }
     }
     /// Returns the number of logical elements in the vector, also referred to as its ‘length’
     #[inline]
     pub fn len(&self) -> usize {
-        self.logical_end - self.logical_start
+        self.len
     }
     /// Returns `true` if the vector contains no logical elements
     #[inline]
     pub fn is_empty(&self) -> bool {
-        self.len() == 0
+        self.len == 0
+    }
+    /// Returns the two slices which together hold the logical contents, in order
+    ///
+    /// If the contents do not wrap around the end of the backing buffer the second slice is empty.
+    /// Use [`make_contiguous`](Self::make_contiguous) to obtain a single slice instead.
+    #[inline]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let cap = self.contents.len();
+        if cap == 0 {
+            return (&[], &[]);
+        }
+        let end = self.head + self.len;
+        if end <= cap {
+            (&self.contents[self.head..end], &[])
+        } else {
+            (&self.contents[self.head..cap], &self.contents[0..end - cap])
+        }
+    }
+    /// Returns the two mutable slices which together hold the logical contents, in order
+    ///
+    /// If the contents do not wrap around the end of the backing buffer the second slice is empty.
+    #[inline]
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let cap = self.contents.len();
+        if cap == 0 {
+            return (&mut [], &mut []);
+        }
+        let end = self.head + self.len;
+        if end <= cap {
+            (&mut self.contents[self.head..end], &mut [])
+        } else {
+            let (front, back) = self.contents.split_at_mut(self.head);
+            (back, &mut front[0..end - cap])
+        }
+    }
+    /// Rearranges the backing buffer so the logical contents occupy a single contiguous slice,
+    /// and returns that slice
+    ///
+    /// This does not drop or reorder any element; inactive elements parked for reuse are simply
+    /// moved behind the logical ones.
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        if self.head != 0 {
+            self.contents.rotate_left(self.head);
+            self.head = 0;
+        }
+        &mut self.contents[0..self.len]
+    }
+    /// Returns an iterator over the logical contents, in order
+    #[inline]
+    pub fn iter(&self) -> core::iter::Chain<core::slice::Iter<'_, T>, core::slice::Iter<'_, T>> {
+        let (front, back) = self.as_slices();
+        front.iter().chain(back.iter())
     }
     /// Appends an element to the back of a vector, increasing the logical length by 1,
     /// creating or reinitializing the element with one of the supplied closures
@@ -75,91 +133,111 @@ impl<T> ReusingQueue<T> {
         NewF: FnOnce() -> T,
         ResetF: FnOnce(&mut T)
     {
-        if self.logical_end < self.contents.len() {
-            reset_f(self.contents.get_mut(self.logical_end).unwrap());
+        let cap = self.contents.len();
+        if self.len < cap {
+            let idx = (self.head + self.len) % cap;
+            reset_f(self.contents.get_mut(idx).unwrap());
         } else {
+            self.make_contiguous();
             self.contents.push(new_f());
         }
-        self.logical_end += 1;
+        self.len += 1;
     }
     /// Removes the last element from the vector
     ///
     /// Returns a mutable reference to the element that was removed, or `None` if the vector was already empty
     #[inline]
     pub fn pop(&mut self) -> Option<&mut T> {
-        if self.logical_end > self.logical_start {
-            self.logical_end -= 1;
-            let old_idx = self.logical_end;
-            if self.logical_end == self.logical_start {
-                self.clear();
-            }
-            self.contents.get_mut(old_idx)
+        if self.len > 0 {
+            self.len -= 1;
+            let idx = (self.head + self.len) % self.contents.len();
+            self.contents.get_mut(idx)
         } else {
-            self.clear();
             None
         }
     }
     /// Removes the first element from the vector
     ///
     /// Returns a mutable reference to the element that was removed, or `None` if the vector was already empty
+    ///
+    /// The returned element is left live in the backing buffer, so it will be recycled by a later
+    /// [`push_with`](Self::push_with) / [`push_mut`](Self::push_mut) once the ring wraps around.
     #[inline]
     pub fn pop_front(&mut self) -> Option<&mut T> {
-        if self.logical_end > self.logical_start {
-            let old_idx = self.logical_start;
-            self.logical_start += 1;
-            if self.logical_end == self.logical_start {
-                self.clear();
-            }
+        if self.len > 0 {
+            let old_idx = self.head;
+            self.head = (self.head + 1) % self.contents.len();
+            self.len -= 1;
             self.contents.get_mut(old_idx)
         } else {
-            self.clear();
             None
         }
     }
-}
-
-impl<T> AsMut<[T]> for ReusingQueue<T> {
-    fn as_mut(&mut self) -> &mut [T] {
-        &mut self.contents[self.logical_start..self.logical_end]
+    /// Retains only the logical elements for which the predicate returns `true`
+    ///
+    /// Unlike [`Vec::retain`], the elements for which the predicate returns `false` are not dropped;
+    /// they are moved past the logical end so they can be recycled by a later
+    /// [`push_with`](Self::push_with) / [`push_mut`](Self::push_mut).
+    #[inline]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|element| f(element));
     }
-}
-
-impl<T> AsRef<[T]> for ReusingQueue<T> {
-    fn as_ref(&self) -> &[T] {
-        &self.contents[self.logical_start..self.logical_end]
+    /// Retains only the logical elements for which the predicate returns `true`, passing a mutable
+    /// reference to each element
+    ///
+    /// As with [`retain`](Self::retain), rejected elements are parked past the logical end rather
+    /// than dropped.
+    #[inline]
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        self.make_contiguous();
+        let mut write = 0;
+        for read in 0..self.len {
+            if f(self.contents.get_mut(read).unwrap()) {
+                if read != write {
+                    self.contents.swap(read, write);
+                }
+                write += 1;
+            }
+        }
+        self.len = write;
     }
-}
-
-impl<T> core::borrow::Borrow<[T]> for ReusingQueue<T> {
-    fn borrow(&self) -> &[T] {
-        &self.contents[self.logical_start..self.logical_end]
+    /// Returns the total number of elements the vector can hold without reallocating
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.contents.capacity()
     }
-}
-
-impl<T> core::borrow::BorrowMut<[T]> for ReusingQueue<T> {
-    fn borrow_mut(&mut self) -> &mut [T] {
-        &mut self.contents[self.logical_start..self.logical_end]
+    /// Reserves capacity for at least `additional` more elements, forwarding to the inner [`Vec`]
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.contents.reserve(additional);
     }
-}
-
-impl<T> core::ops::Deref for ReusingQueue<T> {
-    type Target = [T];
-    fn deref(&self) -> &[T] {
-        &self.contents[self.logical_start..self.logical_end]
+    /// Reserves the minimum capacity for at least `additional` more elements, forwarding to the inner [`Vec`]
+    #[inline]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.contents.reserve_exact(additional);
     }
-}
-
-impl<T> core::ops::DerefMut for ReusingQueue<T> {
-    fn deref_mut(&mut self) -> &mut [T] {
-        &mut self.contents[self.logical_start..self.logical_end]
+    /// Returns the number of inactive elements being kept alive past the logical end for reuse
+    #[inline]
+    pub fn inactive_len(&self) -> usize {
+        self.contents.len() - self.len
+    }
+    /// Drops the inactive elements parked past the logical end and releases their memory
+    ///
+    /// This reclaims the allocations held by elements left alive for reuse (including their inner
+    /// heap allocations), which is worth doing after a buffer that briefly grew large is now small.
+    #[inline]
+    pub fn shrink_to_active(&mut self) {
+        self.make_contiguous();
+        self.contents.truncate(self.len);
+        self.contents.shrink_to_fit();
     }
 }
 
 impl<T> From<Vec<T>> for ReusingQueue<T> {
     fn from(vec: Vec<T>) -> Self {
         Self {
-            logical_start: 0,
-            logical_end: vec.len(),
+            head: 0,
+            len: vec.len(),
             contents: vec
         }
     }
@@ -167,8 +245,8 @@ impl<T> From<Vec<T>> for ReusingQueue<T> {
 
 impl<T> From<ReusingQueue<T>> for Vec<T> {
     fn from(mut vec: ReusingQueue<T>) -> Self {
-        vec.contents.drain(0..vec.logical_start);
-        vec.contents.truncate(vec.logical_end - vec.logical_start);
+        vec.make_contiguous();
+        vec.contents.truncate(vec.len);
         vec.contents
     }
 }
@@ -177,8 +255,8 @@ impl<T, U> FromIterator<U> for ReusingQueue<T> where T: From<U> {
     fn from_iter<I: IntoIterator<Item=U>>(iter: I) -> Self {
         let contents: Vec<T> = iter.into_iter().map(|element| element.into()).collect();
         Self {
-            logical_start: 0,
-            logical_end: contents.len(),
+            head: 0,
+            len: contents.len(),
             contents,
         }
     }
@@ -187,37 +265,34 @@ impl<T, U> FromIterator<U> for ReusingQueue<T> where T: From<U> {
 impl<T> IntoIterator for ReusingQueue<T> {
     type Item = T;
     type IntoIter = ReusingVecIter<T>;
-    fn into_iter(self) -> Self::IntoIter {
-        let mut iter = self.contents.into_iter();
-        if self.logical_start > 0 {
-            iter.nth(self.logical_start - 1);
-        }
-        iter.take(self.logical_end - self.logical_start)
+    fn into_iter(mut self) -> Self::IntoIter {
+        self.make_contiguous();
+        self.contents.into_iter().take(self.len)
     }
 }
 
 impl<T> PartialEq<Self> for ReusingQueue<T> where T: PartialEq {
     fn eq(&self, other: &Self) -> bool {
-        (self as &[T]).eq(other as &[T])
+        self.len == other.len && self.iter().eq(other.iter())
     }
 }
 impl<T> Eq for ReusingQueue<T> where T: Eq {}
 
 impl<T> PartialEq<[T]> for ReusingQueue<T> where T: PartialEq {
     fn eq(&self, other: &[T]) -> bool {
-        (self as &[T]).eq(other)
+        self.len == other.len() && self.iter().eq(other.iter())
     }
 }
 
 impl<T> PartialEq<Vec<T>> for ReusingQueue<T> where T: PartialEq {
     fn eq(&self, other: &Vec<T>) -> bool {
-        (self as &[T]).eq(other)
+        self.len == other.len() && self.iter().eq(other.iter())
     }
 }
 
 impl<T> PartialEq<ReusingVec<T>> for ReusingQueue<T> where T: PartialEq {
     fn eq(&self, other: &ReusingVec<T>) -> bool {
-        (self as &[T]).eq(other as &[T])
+        self.len == other.len() && self.iter().eq((other as &[T]).iter())
     }
 }
 
@@ -226,39 +301,101 @@ impl<T: ReusableElement> ReusingQueue<T> {
     /// a mutable reference to the new / re-initialized element
     #[inline]
     pub fn push_mut(&mut self) -> &mut T {
-        if self.logical_end < self.contents.len() {
-            self.contents.get_mut(self.logical_end).unwrap().reset();
+        let cap = self.contents.len();
+        let idx = if self.len < cap {
+            let idx = (self.head + self.len) % cap;
+            self.contents.get_mut(idx).unwrap().reset();
+            idx
         } else {
+            self.make_contiguous();
             self.contents.push(T::new());
+            self.contents.len() - 1
+        };
+        self.len += 1;
+        self.contents.get_mut(idx).unwrap()
+    }
+    /// Appends an element for each item of `iter`, reinitializing a parked slot in place from the item
+    ///
+    /// This is the reuse-friendly counterpart to [`Extend`]: rather than taking elements by value
+    /// (which would discard the inner allocations of any parked slot), each source item is fed to
+    /// `reset_f` together with a mutable reference to a recycled element.  A new element is created
+    /// with [`ReusableElement::new`] only when the buffer has no parked slot left and must grow.
+    ///
+    /// A recycled slot is reset before `reset_f` runs, so the closure always sees a fresh element
+    /// regardless of whether the buffer grew.
+    #[inline]
+    pub fn push_from_iter<I, F>(&mut self, iter: I, mut reset_f: F)
+        where
+        I: IntoIterator,
+        F: FnMut(&mut T, I::Item)
+    {
+        for item in iter {
+            let cap = self.contents.len();
+            if self.len < cap {
+                let idx = (self.head + self.len) % cap;
+                let slot = self.contents.get_mut(idx).unwrap();
+                slot.reset();
+                reset_f(slot, item);
+            } else {
+                self.make_contiguous();
+                let mut element = T::new();
+                reset_f(&mut element, item);
+                self.contents.push(element);
+            }
+            self.len += 1;
+        }
+    }
+    /// Resizes the logical length to `n`, recycling parked slots through `f`
+    ///
+    /// This is analogous to [`Vec::resize_with`], but when growing it reuses the elements parked
+    /// past the logical end (reset and passed to `f`) instead of constructing fresh ones, and when
+    /// shrinking it parks the surplus elements rather than dropping them.
+    #[inline]
+    pub fn fill_active_with<F: FnMut(&mut T)>(&mut self, n: usize, mut f: F) {
+        if n <= self.len {
+            self.len = n;
+            return;
+        }
+        while self.len < n {
+            let cap = self.contents.len();
+            if self.len < cap {
+                let idx = (self.head + self.len) % cap;
+                let slot = self.contents.get_mut(idx).unwrap();
+                slot.reset();
+                f(slot);
+            } else {
+                self.make_contiguous();
+                let mut element = T::new();
+                f(&mut element);
+                self.contents.push(element);
+            }
+            self.len += 1;
         }
-        let element = self.contents.get_mut(self.logical_end).unwrap();
-        self.logical_end += 1;
-        element
     }
 }
 
 #[test]
 fn queue_test() {
-    let mut queue: ReusingQueue<i32> = (0..10).into_iter().collect();
+    let mut queue: ReusingQueue<i32> = (0..10).collect();
 
     assert_eq!(queue.len(), 10);
-    assert_eq!(*queue, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    assert_eq!(queue.make_contiguous(), &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
     queue.truncate(9);
     assert_eq!(queue.len(), 9);
-    assert_eq!(*queue, [0, 1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(queue.make_contiguous(), &[0, 1, 2, 3, 4, 5, 6, 7, 8]);
     assert_eq!(queue.pop(), Some(&mut 8));
 
     queue.pop();
     assert_eq!(queue.pop_front(), Some(&mut 0));
     assert_eq!(queue.len(), 6);
-    assert_eq!(*queue, [1, 2, 3, 4, 5, 6]);
+    assert_eq!(queue.make_contiguous(), &[1, 2, 3, 4, 5, 6]);
 
     queue.truncate(5);
     assert_eq!(queue.len(), 5);
-    assert_eq!(*queue, [1, 2, 3, 4, 5]);
+    assert_eq!(queue.make_contiguous(), &[1, 2, 3, 4, 5]);
 
     let vec_1: Vec<i32> = queue.clone().into_iter().collect();
-    assert_eq!(*vec_1, *queue);
+    assert_eq!(queue, vec_1);
 
     let vec_2: Vec<i32> = queue.clone().into();
     assert_eq!(vec_1, vec_2);
@@ -268,4 +405,31 @@ fn queue_test() {
     }
     assert_eq!(queue.len(), 0);
     assert_eq!(queue.pop_front(), None);
-}
\ No newline at end of file
+}
+
+#[test]
+fn queue_ring_reuse_test() {
+    // A steady push/pop_front FIFO must recycle the front slots rather than grow without bound
+    let mut queue: ReusingQueue<alloc::string::String> = ReusingQueue::new();
+    for _ in 0..4 {
+        queue.push_mut().push_str("seed");
+    }
+    let cap_after_seed = queue.contents.len();
+    for _ in 0..100 {
+        queue.pop_front().unwrap();
+        queue.push_mut().push('x');
+        assert_eq!(queue.len(), 4);
+    }
+    // The physical buffer never had to grow past the working-set size
+    assert_eq!(queue.contents.len(), cap_after_seed);
+    assert_eq!(queue.len(), 4);
+
+    // Growth across the wrap boundary keeps the logical order intact
+    queue.clear();
+    let mut wrapped: ReusingQueue<i32> = ReusingQueue::new();
+    for i in 0..3 { wrapped.push_with(|| i, |e| *e = i); }
+    wrapped.pop_front();
+    wrapped.pop_front();
+    for i in 3..8 { wrapped.push_with(|| i, |e| *e = i); }
+    assert_eq!(wrapped, alloc::vec![2, 3, 4, 5, 6, 7]);
+}